@@ -141,30 +141,26 @@
 //!
 //! impl RawFoo {
 //!     pub fn new(flags: u32) -> impl PinInit<Self, Error> {
-//!         // SAFETY:
-//!         // - when the closure returns `Ok(())`, then it has successfully initialized and
-//!         //   enabled `foo`,
-//!         // - when it returns `Err(e)`, then it has cleaned up before
-//!         unsafe {
+//!         // SAFETY: when the closure returns `Ok(())`, then it has successfully initialized
+//!         // `foo`; `_p` is a ZST, so all fields of `RawFoo` are then initialized.
+//!         let foo = unsafe {
 //!             init::pin_init_from_closure(move |slot: *mut Self| {
 //!                 // `slot` contains uninit memory, avoid creating a reference.
-//!                 let foo = addr_of_mut!((*slot).foo);
-//!
-//!                 // Initialize the `foo`
-//!                 bindings::init_foo(Opaque::raw_get(foo));
-//!
-//!                 // Try to enable it.
-//!                 let err = bindings::enable_foo(Opaque::raw_get(foo), flags);
-//!                 if err != 0 {
-//!                     // Enabling has failed, first clean up the foo and then return the error.
-//!                     bindings::destroy_foo(Opaque::raw_get(foo));
-//!                     return Err(Error::from_kernel_errno(err));
-//!                 }
-//!
-//!                 // All fields of `RawFoo` have been initialized, since `_p` is a ZST.
+//!                 bindings::init_foo(Opaque::raw_get(addr_of_mut!((*slot).foo)));
 //!                 Ok(())
 //!             })
-//!         }
+//!         };
+//!         // Enabling can only fail once `foo` has been initialized, so do it as a `pin_chain`
+//!         // step: if it returns `Err`, the already-initialized `RawFoo` (and thus `foo`) is
+//!         // dropped automatically, which runs `PinnedDrop` and cleans `foo` up for us.
+//!         foo.pin_chain(move |this| {
+//!             // SAFETY: `foo` was initialized by the closure above.
+//!             let err = unsafe { bindings::enable_foo(this.foo.get(), flags) };
+//!             if err != 0 {
+//!                 return Err(Error::from_kernel_errno(err));
+//!             }
+//!             Ok(())
+//!         })
 //!     }
 //! }
 //!
@@ -200,7 +196,7 @@
 
 use crate::{
     error::{self, Error},
-    sync::UniqueArc,
+    sync::{Arc, UniqueArc},
 };
 use alloc::boxed::Box;
 use core::{
@@ -330,6 +326,33 @@ macro_rules! stack_pin_init {
 /// A normal `let` binding with optional type annotation. The expression is expected to implement
 /// [`PinInit`]/[`Init`]. This macro assigns a result to the given variable, adding a `?` after the
 /// `=` will propagate this error.
+///
+/// Since the initializer is a plain [`PinInit`] value, the usual combinators apply before it ever
+/// reaches the macro, e.g. chaining on a post-init step with [`pin_chain`] or adapting the error
+/// type with [`map_err`] so it lines up with the function's own error type:
+///
+/// ```rust
+/// # #![allow(clippy::disallowed_names, clippy::new_ret_no_self)]
+/// # use kernel::{init, pin_init, stack_try_pin_init, init::*, sync::Mutex, new_mutex};
+/// # use macros::pin_data;
+/// # use core::pin::Pin;
+/// #[pin_data]
+/// struct Foo {
+///     #[pin]
+///     a: Mutex<usize>,
+/// }
+///
+/// stack_try_pin_init!(let foo: Pin<&mut Foo> =? pin_init!(Foo {
+///     a <- Mutex::new(42),
+/// }).pin_chain(|foo| {
+///     pr_info!("a: {}", &*foo.a.lock());
+///     Ok(())
+/// }));
+/// # Ok::<_, core::convert::Infallible>(())
+/// ```
+///
+/// [`pin_chain`]: PinInit::pin_chain
+/// [`map_err`]: PinInit::map_err
 #[macro_export]
 macro_rules! stack_try_pin_init {
     (let $var:ident $(: $t:ty)? = $val:expr) => {
@@ -537,18 +560,65 @@ macro_rules! stack_try_pin_init {
 ///
 /// [`try_pin_init!`]: kernel::try_pin_init
 /// [`NonNull<Self>`]: core::ptr::NonNull
+///
+/// # Enum initializers
+///
+/// `pin_init!` also accepts an `enum $Enum::$Variant { $($fields)* }` form to build an
+/// `impl PinInit<$Enum, $Error>` for a single, chosen variant:
+///
+/// ```rust
+/// # use kernel::{pin_init, init::PinInit, InPlaceInit};
+/// enum State {
+///     Idle,
+///     Active { count: u32 },
+/// }
+/// # fn demo() -> impl PinInit<State> {
+/// pin_init!(enum State::Active {
+///     count: 0,
+/// })
+/// # }
+/// # Box::pin_init(demo()).unwrap();
+/// ```
+///
+/// Unlike the `struct` form, fields here are plain values (`$field: $value`, not `$field <-
+/// $initializer`): the whole variant is built in a local first and then moved into the slot with
+/// a single write, so there is no notion of projecting into one of its fields while it is still
+/// partially initialized. That rules out `#[pin]` fields and nested in-place initializers
+/// (`<-`) within the variant — both would need the same kind of per-field, discriminant-aware
+/// slot accessor that `#[pin_data]` generates for `struct`s, and which the `#[pin_data]`
+/// proc-macro does not generate for `enum`s in this tree. A variant that only needs ordinary,
+/// already-constructed field values (as above) does not need that accessor and works today;
+/// a variant with a `#[pin]` field or an in-place sub-initializer is out of scope until
+/// `#[pin_data]` grows enum support.
+///
+/// The `enum` form is only accepted by `pin_init!` (with the error type [`Infallible`]);
+/// [`try_pin_init!`], [`init!`] and [`try_init!`] only accept the `struct` form.
 // For a detailed example of how this macro works, see the module documentation of the hidden
 // module `__internal` inside of `init/__internal.rs`.
 #[macro_export]
 macro_rules! pin_init {
+    ($(&$this:ident in)? enum $t:ident $(::<$($generics:ty),* $(,)?>)? :: $variant:ident {
+        $($fields:tt)*
+    }) => {
+        $crate::try_pin_init!(enum:
+            @this($($this)?),
+            @typ($t $(::<$($generics),*>)?),
+            @variant($variant),
+            @fields($($fields)*),
+            @error(::core::convert::Infallible),
+        )
+    };
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
-        $crate::try_pin_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error(::core::convert::Infallible),
+            @data(PinData, use_data),
+            @has_data(HasPinData, __pin_data),
+            @construct_closure(pin_init_from_closure),
             @munch_fields($($fields)*),
         )
     };
@@ -595,274 +665,56 @@ macro_rules! pin_init {
 // module `__internal` inside of `init/__internal.rs`.
 #[macro_export]
 macro_rules! try_pin_init {
+    (enum:
+        @this($($this:ident)?),
+        @typ($t:ident $(::<$($generics:ty),*>)?),
+        @variant($variant:ident),
+        @fields($($fields:tt)*),
+        @error($err:ty),
+    ) => {{
+        let init = move |slot: *mut $t $(::<$($generics),*>)?| -> ::core::result::Result<(), $err> {
+            // SAFETY: `slot` is not read through `$this`, only its address is taken; the
+            // pointee may still be uninitialized at this point.
+            $(let $this = unsafe { ::core::ptr::NonNull::new_unchecked(slot) };)?
+            let val = $t::$variant { $($fields)* };
+            // SAFETY: `slot` is valid for writes, and `val` is a fully constructed value, so
+            // this single write leaves `slot` completely initialized.
+            unsafe { ::core::ptr::write(slot, val) };
+            Ok(())
+        };
+        // SAFETY: the closure above writes a fully initialized value to `slot` in one go (or
+        // does not write to `slot` at all, if constructing `val` returns `Err` first), and it
+        // never moves out of `slot` afterwards.
+        unsafe { $crate::init::pin_init_from_closure::<_, $err>(init) }
+    }};
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
-        $crate::try_pin_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)? ),
             @fields($($fields)*),
             @error($crate::error::Error),
+            @data(PinData, use_data),
+            @has_data(HasPinData, __pin_data),
+            @construct_closure(pin_init_from_closure),
             @munch_fields($($fields)*),
         )
     };
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }? $err:ty) => {
-        $crate::try_pin_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)? ),
             @fields($($fields)*),
             @error($err),
+            @data(PinData, use_data),
+            @has_data(HasPinData, __pin_data),
+            @construct_closure(pin_init_from_closure),
             @munch_fields($($fields)*),
         )
     };
-    (parse_zeroable_end:
-        @this($($this:ident)?),
-        @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
-        @fields($($fields:tt)*),
-        @error($err:ty),
-        @munch_fields(),
-    ) => {
-        $crate::try_pin_init!(
-            @this($($this)?),
-            @typ($t $(::$p)* $(::<$($generics),*>)?),
-            @fields($($fields)*),
-            @error($err),
-            @zeroed(), // nothing means we do not zero unmentioned fields
-        )
-    };
-    (parse_zeroable_end:
-        @this($($this:ident)?),
-        @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
-        @fields($($fields:tt)*),
-        @error($err:ty),
-        @munch_fields(..Zeroable::zeroed()),
-    ) => {
-        $crate::try_pin_init!(
-            @this($($this)?),
-            @typ($t $(::$p)* $(::<$($generics),*>)?),
-            @fields($($fields)*),
-            @error($err),
-            @zeroed(()), // () means we zero unmentioned fields
-        )
-    };
-    (parse_zeroable_end:
-        @this($($this:ident)?),
-        @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
-        @fields($($fields:tt)*),
-        @error($err:ty),
-        @munch_fields($ignore:tt $($rest:tt)*),
-    ) => {
-        $crate::try_pin_init!(parse_zeroable_end:
-            @this($($this)?),
-            @typ($t $(::$p)* $(::<$($generics),*>)?),
-            @fields($($fields)*),
-            @error($err),
-            @munch_fields($($rest)*),
-         )
-     };
-    (
-        @this($($this:ident)?),
-        @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
-        @fields($($fields:tt)*),
-        @error($err:ty),
-        @zeroed($($init_zeroed:expr)?),
-    ) => {{
-        // We do not want to allow arbitrary returns, so we declare this type as the `Ok` return
-        // type and shadow it later when we insert the arbitrary user code. That way there will be
-        // no possibility of returning without `unsafe`.
-        struct __InitOk;
-        // Get the pin data from the supplied type.
-        let data = unsafe {
-            use $crate::init::__internal::HasPinData;
-            $t$(::$p)*$(::<$($generics),*>)?::__pin_data()
-        };
-        // Ensure that `data` really is of type `PinData` and help with type inference:
-        let init = $crate::init::__internal::PinData::make_closure::<_, __InitOk, $err>(
-            data,
-            move |slot| {
-                {
-                    // Shadow the structure so it cannot be used to return early.
-                    struct __InitOk;
-                    // If `$init_zeroed` is present, we should not error on unmentioned fields and
-                    // set the whole struct to zero first.
-                    //
-                    // For type inference reasons we do not use `init::zeroed`, but instead
-                    // write_bytes.
-                    $({
-                        // We need to ensure the type actually implements `Zeroable`.
-                        fn is_zeroable<T: Zeroable>(ptr: *mut T) {}
-                        is_zeroable(slot);
-                        // SAFETY: the type implements `Zeroable`.
-                        unsafe { ::core::ptr::write_bytes(slot, 0, 1) };
-                        $init_zeroed
-                    })?
-                    // Create the `this` so it can be referenced by the user inside of the
-                    // expressions creating the individual fields.
-                    $(let $this = unsafe { ::core::ptr::NonNull::new_unchecked(slot) };)?
-                    // Initialize every field.
-                    $crate::try_pin_init!(init_slot:
-                        @data(data),
-                        @slot(slot),
-                        @guards(),
-                        @munch_fields($($fields)*,),
-                    );
-                    // We use unreachable code to ensure that all fields have been mentioned exactly
-                    // once, this struct initializer will still be type-checked and complain with a
-                    // very natural error message if a field is forgotten/mentioned more than once.
-                    #[allow(unreachable_code, clippy::diverging_sub_expression, clippy::redundant_closure_call)]
-                    if false {
-                        (|| {
-                            $crate::try_pin_init!(make_initializer:
-                                @slot(slot),
-                                @type_name($t$(::$p)*),
-                                @munch_fields($($fields)*,),
-                                @acc(),
-                            );
-                        })();
-                    }
-                }
-                Ok(__InitOk)
-            }
-        );
-        let init = move |slot| -> ::core::result::Result<(), $err> {
-            init(slot).map(|__InitOk| ())
-        };
-        let init = unsafe { $crate::init::pin_init_from_closure::<_, $err>(init) };
-        init
-    }};
-    (init_slot:
-        @data($data:ident),
-        @slot($slot:ident),
-        @guards($($guards:ident,)*),
-        @munch_fields($(..Zeroable::zeroed())? $(,)?),
-    ) => {
-        // Endpoint of munching, no fields are left. If execution reaches this point, all fields
-        // have been initialized. Therefore we can now dismiss the guards by forgetting them.
-        $(::core::mem::forget($guards);)*
-    };
-    (init_slot:
-        @data($data:ident),
-        @slot($slot:ident),
-        @guards($($guards:ident,)*),
-        // In-place initialization syntax.
-        @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
-    ) => {
-        let $field = $val;
-        // Call the initializer.
-        //
-        // SAFETY: `slot` is valid, because we are inside of an initializer closure, we
-        // return when an error/panic occurs.
-        // We also use the `data` to require the correct trait (`Init` or `PinInit`) for `$field`.
-        unsafe { $data.$field(::core::ptr::addr_of_mut!((*$slot).$field), $field)? };
-        // Create the drop guard.
-        //
-        // Users cannot access this field due to macro hygiene.
-        //
-        // SAFETY: We forget the guard later when initialization has succeeded.
-        let guard = unsafe {
-            $crate::init::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
-        };
-
-        $crate::try_pin_init!(init_slot:
-            @data($data),
-            @slot($slot),
-            @guards(guard, $($guards,)*),
-            @munch_fields($($rest)*),
-        );
-    };
-    (init_slot:
-        @data($data:ident),
-        @slot($slot:ident),
-        @guards($($guards:ident,)*),
-        // Direct value init, this is safe for every field.
-        @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
-    ) => {
-        $(let $field = $val;)?
-        // Initialize the field.
-        //
-        // SAFETY: The memory at `slot` is uninitialized.
-        unsafe { ::core::ptr::write(::core::ptr::addr_of_mut!((*$slot).$field), $field) };
-        // Create the drop guard:
-        //
-        // Users cannot access this field due to macro hygiene.
-        //
-        // SAFETY: We forget the guard later when initialization has succeeded.
-        let guard = unsafe {
-            $crate::init::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
-        };
-
-        $crate::try_pin_init!(init_slot:
-            @data($data),
-            @slot($slot),
-            @guards(guard, $($guards,)*),
-            @munch_fields($($rest)*),
-        );
-    };
-    (make_initializer:
-        @slot($slot:ident),
-        @type_name($t:ident $(::$p:ident)*),
-        @munch_fields(..Zeroable::zeroed() $(,)?),
-        @acc($($acc:tt)*),
-    ) => {
-        // Endpoint, nothing more to munch, create the initializer. Without erroring on extra
-        // fields. We want to have the same semantics as a struct initializer with struct update
-        // syntax, so we create one first.
-        // Since we are in the `if false` branch, this will never get executed. We abuse `slot` to
-        // get the correct type inference here:
-        unsafe {
-            // We have to force zeroed to have the correct type.
-            let mut zeroed = ::core::mem::zeroed();
-            ::core::ptr::write($slot, zeroed);
-            zeroed = ::core::mem::zeroed();
-            ::core::ptr::write($slot, $t$(::$p)* {
-                $($acc)*
-                ..zeroed
-            });
-        }
-    };
-    (make_initializer:
-        @slot($slot:ident),
-        @type_name($t:ident $(::$p:ident)*),
-        @munch_fields($(,)?),
-        @acc($($acc:tt)*),
-    ) => {
-        // Endpoint, nothing more to munch, create the initializer.
-        // Since we are in the `if false` branch, this will never get executed. We abuse `slot` to
-        // get the correct type inference here:
-        unsafe {
-            ::core::ptr::write($slot, $t$(::$p)* {
-                $($acc)*
-            });
-        }
-    };
-    (make_initializer:
-        @slot($slot:ident),
-        @type_name($t:ident $(::$p:ident)*),
-        @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
-        @acc($($acc:tt)*),
-    ) => {
-        $crate::try_pin_init!(make_initializer:
-            @slot($slot),
-            @type_name($t$(::$p)*),
-            @munch_fields($($rest)*),
-            @acc($($acc)* $field: ::core::panic!(),),
-        );
-    };
-    (make_initializer:
-        @slot($slot:ident),
-        @type_name($t:ident $(::$p:ident)*),
-        @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
-        @acc($($acc:tt)*),
-    ) => {
-        $crate::try_pin_init!(make_initializer:
-            @slot($slot),
-            @type_name($t$(::$p)*),
-            @munch_fields($($rest)*),
-            @acc($($acc)* $field: ::core::panic!(),),
-        );
-    };
 }
 
 /// Construct an in-place initializer for `struct`s.
@@ -885,11 +737,14 @@ macro_rules! init {
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
-        $crate::try_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error(::core::convert::Infallible),
+            @data(InitData),
+            @has_data(HasInitData, __init_data),
+            @construct_closure(init_from_closure),
             @munch_fields($($fields)*),
         )
     }
@@ -933,38 +788,75 @@ macro_rules! try_init {
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }) => {
-        $crate::try_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($crate::error::Error),
+            @data(InitData),
+            @has_data(HasInitData, __init_data),
+            @construct_closure(init_from_closure),
             @munch_fields($($fields)*),
         )
     };
     ($(&$this:ident in)? $t:ident $(::$p:ident)* $(::<$($generics:ty),* $(,)?>)? {
         $($fields:tt)*
     }? $err:ty) => {
-        $crate::try_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
+            @data(InitData),
+            @has_data(HasInitData, __init_data),
+            @construct_closure(init_from_closure),
             @munch_fields($($fields)*),
         )
     };
+}
+
+/// The guts of [`pin_init!`], [`try_pin_init!`], [`init!`] and [`try_init!`].
+///
+/// All four public macros parse an almost-identical `struct` initializer and only differ in:
+/// - which per-field accessor they call for `<-` fields (`PinData`'s structurally-pinning
+///   `$data.$field(...)` vs. calling `Init::__init` directly, since plain `init!`/`try_init!` has
+///   no notion of `#[pin]` fields),
+/// - which trait/method pair fetches that accessor (`HasPinData`/`__pin_data` vs.
+///   `HasInitData`/`__init_data`),
+/// - which closure constructor wraps the result (`pin_init_from_closure` vs.
+///   `init_from_closure`).
+///
+/// Rather than four near-identical copies of the field-munching state machine (which had
+/// already drifted subtly between the pinned and non-pinned forms), the four public macros are
+/// thin wrappers that forward here, tagging the three differences above as `@data`/`@has_data`/
+/// `@construct_closure`. `@data(PinData, use_data)` additionally carries the `use_data` marker
+/// that selects the `<-` arm routing through `$data.$field(...)`; `@data(InitData)` (no marker)
+/// selects the arm that calls `Init::__init` directly.
+///
+/// For a detailed example of how the munching works, see the module documentation of the hidden
+/// module `__internal` inside of `init/__internal.rs`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __init_internal {
     (parse_zeroable_end:
         @this($($this:ident)?),
         @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
+        @data($data_ty:ident $(, $use_data:ident)?),
+        @has_data($has_data:ident, $get_data:ident),
+        @construct_closure($construct_closure:ident),
         @munch_fields(),
     ) => {
-        $crate::try_init!(
+        $crate::__init_internal!(
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
-            @zeroed(), // Nothing means we do not zero unmentioned fields.
+            @data($data_ty $(, $use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @zeroed(), // nothing means we do not zero unmentioned fields
         )
     };
     (parse_zeroable_end:
@@ -972,14 +864,20 @@ macro_rules! try_init {
         @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
+        @data($data_ty:ident $(, $use_data:ident)?),
+        @has_data($has_data:ident, $get_data:ident),
+        @construct_closure($construct_closure:ident),
         @munch_fields(..Zeroable::zeroed()),
     ) => {
-        $crate::try_init!(
+        $crate::__init_internal!(
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
-            @zeroed(()), // () means we zero unmentioned fields.
+            @data($data_ty $(, $use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
+            @zeroed(()), // () means we zero unmentioned fields
         )
     };
     (parse_zeroable_end:
@@ -987,13 +885,19 @@ macro_rules! try_init {
         @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
+        @data($data_ty:ident $(, $use_data:ident)?),
+        @has_data($has_data:ident, $get_data:ident),
+        @construct_closure($construct_closure:ident),
         @munch_fields($ignore:tt $($rest:tt)*),
     ) => {
-        $crate::try_init!(parse_zeroable_end:
+        $crate::__init_internal!(parse_zeroable_end:
             @this($($this)?),
             @typ($t $(::$p)* $(::<$($generics),*>)?),
             @fields($($fields)*),
             @error($err),
+            @data($data_ty $(, $use_data)?),
+            @has_data($has_data, $get_data),
+            @construct_closure($construct_closure),
             @munch_fields($($rest)*),
          )
      };
@@ -1002,19 +906,22 @@ macro_rules! try_init {
         @typ($t:ident $(::$p:ident)* $(::<$($generics:ty),*>)?),
         @fields($($fields:tt)*),
         @error($err:ty),
+        @data($data_ty:ident $(, $use_data:ident)?),
+        @has_data($has_data:ident, $get_data:ident),
+        @construct_closure($construct_closure:ident),
         @zeroed($($init_zeroed:expr)?),
     ) => {{
         // We do not want to allow arbitrary returns, so we declare this type as the `Ok` return
         // type and shadow it later when we insert the arbitrary user code. That way there will be
         // no possibility of returning without `unsafe`.
         struct __InitOk;
-        // Get the init data from the supplied type.
+        // Get the pin/init data from the supplied type.
         let data = unsafe {
-            use $crate::init::__internal::HasInitData;
-            $t$(::$p)*$(::<$($generics),*>)?::__init_data()
+            use $crate::init::__internal::$has_data;
+            $t$(::$p)*$(::<$($generics),*>)?::$get_data()
         };
-        // Ensure that `data` really is of type `InitData` and help with type inference:
-        let init = $crate::init::__internal::InitData::make_closure::<_, __InitOk, $err>(
+        // Ensure that `data` really is of type `PinData`/`InitData` and help with type inference:
+        let init = $crate::init::__internal::$data_ty::make_closure::<_, __InitOk, $err>(
             data,
             move |slot| {
                 {
@@ -1037,7 +944,9 @@ macro_rules! try_init {
                     // expressions creating the individual fields.
                     $(let $this = unsafe { ::core::ptr::NonNull::new_unchecked(slot) };)?
                     // Initialize every field.
-                    $crate::try_init!(init_slot:
+                    $crate::__init_internal!(init_slot:
+                        @data(data),
+                        @use_data($($use_data)?),
                         @slot(slot),
                         @guards(),
                         @munch_fields($($fields)*,),
@@ -1048,7 +957,7 @@ macro_rules! try_init {
                     #[allow(unreachable_code, clippy::diverging_sub_expression, clippy::redundant_closure_call)]
                     if false {
                         (|| {
-                            $crate::try_init!(make_initializer:
+                            $crate::__init_internal!(make_initializer:
                                 @slot(slot),
                                 @type_name($t$(::$p)*),
                                 @munch_fields($($fields)*,),
@@ -1063,10 +972,12 @@ macro_rules! try_init {
         let init = move |slot| -> ::core::result::Result<(), $err> {
             init(slot).map(|__InitOk| ())
         };
-        let init = unsafe { $crate::init::init_from_closure::<_, $err>(init) };
+        let init = unsafe { $crate::init::$construct_closure::<_, $err>(init) };
         init
     }};
     (init_slot:
+        @data($data:ident),
+        @use_data($($use_data:ident)?),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
         @munch_fields($(..Zeroable::zeroed())? $(,)?),
@@ -1076,8 +987,45 @@ macro_rules! try_init {
         $(::core::mem::forget($guards);)*
     };
     (init_slot:
+        @data($data:ident),
+        @use_data(use_data),
+        @slot($slot:ident),
+        @guards($($guards:ident,)*),
+        // In-place initialization syntax, routed through the field's `PinData` accessor so it is
+        // required to be pin-initialized when the field is `#[pin]`.
+        @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
+    ) => {
+        let $field = $val;
+        // Call the initializer.
+        //
+        // SAFETY: `slot` is valid, because we are inside of an initializer closure, we
+        // return when an error/panic occurs.
+        // We also use the `data` to require the correct trait (`Init` or `PinInit`) for `$field`.
+        unsafe { $data.$field(::core::ptr::addr_of_mut!((*$slot).$field), $field)? };
+        // Create the drop guard.
+        //
+        // Users cannot access this field due to macro hygiene.
+        //
+        // SAFETY: We forget the guard later when initialization has succeeded.
+        let guard = unsafe {
+            $crate::init::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
+        };
+
+        $crate::__init_internal!(init_slot:
+            @data($data),
+            @use_data(use_data),
+            @slot($slot),
+            @guards(guard, $($guards,)*),
+            @munch_fields($($rest)*),
+        );
+    };
+    (init_slot:
+        @data($data:ident),
+        @use_data(),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
+        // In-place initialization syntax, calling `Init::__init` directly: `init!`/`try_init!`
+        // has no notion of `#[pin]` fields, so there is no per-field accessor to route through.
         @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
     ) => {
         {
@@ -1099,26 +1047,28 @@ macro_rules! try_init {
             $crate::init::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
         };
 
-        $crate::try_init!(init_slot:
+        $crate::__init_internal!(init_slot:
+            @data($data),
+            @use_data(),
             @slot($slot),
             @guards(guard, $($guards,)*),
             @munch_fields($($rest)*),
         );
     };
     (init_slot:
+        @data($data:ident),
+        @use_data($($use_data:ident)?),
         @slot($slot:ident),
         @guards($($guards:ident,)*),
-        // Direct value init.
+        // Direct value init, this is safe for every field.
         @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
     ) => {
-        {
-            $(let $field = $val;)?
-            // Call the initializer.
-            //
-            // SAFETY: The memory at `slot` is uninitialized.
-            unsafe { ::core::ptr::write(::core::ptr::addr_of_mut!((*$slot).$field), $field) };
-        }
-        // Create the drop guard.
+        $(let $field = $val;)?
+        // Initialize the field.
+        //
+        // SAFETY: The memory at `slot` is uninitialized.
+        unsafe { ::core::ptr::write(::core::ptr::addr_of_mut!((*$slot).$field), $field) };
+        // Create the drop guard:
         //
         // Users cannot access this field due to macro hygiene.
         //
@@ -1127,7 +1077,9 @@ macro_rules! try_init {
             $crate::init::__internal::DropGuard::new(::core::ptr::addr_of_mut!((*$slot).$field))
         };
 
-        $crate::try_init!(init_slot:
+        $crate::__init_internal!(init_slot:
+            @data($data),
+            @use_data($($use_data)?),
             @slot($slot),
             @guards(guard, $($guards,)*),
             @munch_fields($($rest)*),
@@ -1176,11 +1128,11 @@ macro_rules! try_init {
         @munch_fields($field:ident <- $val:expr, $($rest:tt)*),
         @acc($($acc:tt)*),
     ) => {
-        $crate::try_init!(make_initializer:
+        $crate::__init_internal!(make_initializer:
             @slot($slot),
             @type_name($t$(::$p)*),
             @munch_fields($($rest)*),
-            @acc($($acc)*$field: ::core::panic!(),),
+            @acc($($acc)* $field: ::core::panic!(),),
         );
     };
     (make_initializer:
@@ -1189,11 +1141,11 @@ macro_rules! try_init {
         @munch_fields($field:ident $(: $val:expr)?, $($rest:tt)*),
         @acc($($acc:tt)*),
     ) => {
-        $crate::try_init!(make_initializer:
+        $crate::__init_internal!(make_initializer:
             @slot($slot),
             @type_name($t$(::$p)*),
             @munch_fields($($rest)*),
-            @acc($($acc)*$field: ::core::panic!(),),
+            @acc($($acc)* $field: ::core::panic!(),),
         );
     };
 }
@@ -1232,6 +1184,98 @@ pub unsafe trait PinInit<T: ?Sized, E = Infallible>: Sized {
     ///   deallocate.
     /// - `slot` will not move until it is dropped, i.e. it will be pinned.
     unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E>;
+
+    /// First initializes the value using `self` and then calls the given closure with a pinned
+    /// mutable reference to the initialized value.
+    ///
+    /// If `f` returns `Err`, then the value is dropped and the initializer will forward the
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kernel::sync::Mutex;
+    /// let mtx = Mutex::new_named(42, "Foo::mtx").pin_chain(|_mtx| Ok(()));
+    /// ```
+    fn pin_chain<F>(self, f: F) -> ChainPinInit<Self, F, T, E>
+    where
+        F: FnOnce(Pin<&mut T>) -> Result<(), E>,
+    {
+        ChainPinInit(self, f, PhantomData)
+    }
+
+    /// Converts this pin-initializer into one with a different error type.
+    ///
+    /// The underlying initializer is run completely unmodified; only the returned `Err` is
+    /// mapped using `f`. Since the underlying initializer already cleaned up `slot` before
+    /// returning `Err`, no additional cleanup is necessary here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kernel::{error::Error, sync::Mutex};
+    /// let mtx = Mutex::new_named(42, "Foo::mtx")
+    ///     .map_err(|e: core::convert::Infallible| Error::from(e));
+    /// ```
+    fn map_err<F, E2>(self, f: F) -> MapErr<Self, F, T, E>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        MapErr(self, f, PhantomData)
+    }
+}
+
+/// A pin-initializer that forwards the slot to the inner pin-initializer unchanged, but maps the
+/// error of the inner initializer using the given closure.
+///
+/// Created by [`PinInit::map_err`].
+#[doc(hidden)]
+pub struct MapErr<I, F, T: ?Sized, E>(I, F, PhantomData<fn(*mut T) -> E>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` and cleans up the slot on error.
+unsafe impl<T: ?Sized, E, E2, I, F> PinInit<T, E2> for MapErr<I, F, T, E>
+where
+    I: PinInit<T, E>,
+    F: FnOnce(E) -> E2,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E2> {
+        // SAFETY: `slot` is valid, this is the exact same contract as `__pinned_init`. The inner
+        // initializer already cleaned up `slot` on error, so no additional cleanup is needed
+        // here; we only translate the error value.
+        unsafe { self.0.__pinned_init(slot).map_err(self.1) }
+    }
+}
+
+/// An initializer that first initializes the value using `I` and then calls `F` with a pinned
+/// mutable reference to that value.
+///
+/// Created by [`PinInit::pin_chain`].
+#[doc(hidden)]
+pub struct ChainPinInit<I, F, T: ?Sized, E>(I, F, PhantomData<fn(*mut T) -> E>);
+
+// SAFETY: The `__pinned_init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` and cleans up the slot on error.
+unsafe impl<T: ?Sized, E, I, F> PinInit<T, E> for ChainPinInit<I, F, T, E>
+where
+    I: PinInit<T, E>,
+    F: FnOnce(Pin<&mut T>) -> Result<(), E>,
+{
+    unsafe fn __pinned_init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `slot` is valid and will not be moved, this is the exact same contract as
+        // `__pinned_init`.
+        unsafe { self.0.__pinned_init(slot)? };
+        // SAFETY: `slot` is now pinned and initialized, since the above call succeeded.
+        let val = unsafe { Pin::new_unchecked(&mut *slot) };
+        (self.1)(val).map_err(|e| {
+            // SAFETY: `slot` was initialized above and since we return `Err` here, the caller
+            // will consider the memory at `slot` to be uninitialized.
+            unsafe { ptr::drop_in_place(slot) };
+            e
+        })
+    }
 }
 
 /// An initializer for `T`.
@@ -1273,55 +1317,80 @@ pub unsafe trait Init<T: ?Sized, E = Infallible>: Sized {
     /// - the caller does not touch `slot` when `Err` is returned, they are only permitted to
     ///   deallocate.
     unsafe fn __init(self, slot: *mut T) -> Result<(), E>;
+
+    /// First initializes the value using `self` and then calls the given closure with a mutable
+    /// reference to the initialized value.
+    ///
+    /// If `f` returns `Err`, then the value is dropped and the initializer will forward the
+    /// error.
+    fn chain<F>(self, f: F) -> ChainInit<Self, F, T, E>
+    where
+        F: FnOnce(&mut T) -> Result<(), E>,
+    {
+        ChainInit(self, f, PhantomData)
+    }
+
+    /// Converts this initializer into one with a different error type.
+    ///
+    /// The underlying initializer is run completely unmodified; only the returned `Err` is
+    /// mapped using `f`. Since the underlying initializer already cleaned up `slot` before
+    /// returning `Err`, no additional cleanup is necessary here.
+    fn map_err<F, E2>(self, f: F) -> MapErrInit<Self, F, T, E>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        MapErrInit(self, f, PhantomData)
+    }
 }
 
-/// Chains a closure to the initializer to be called on successful initialization.
+/// An initializer that forwards the slot to the inner initializer unchanged, but maps the error
+/// of the inner initializer using the given closure.
 ///
-/// Returns a new initializer. If the closure returns `Err`, the object is
-/// dropped.
-// TODO: Once return_position_impl_trait_in_trait works, this should probably be
-// a trait method and called `and_then()` or so.
-pub fn chain<T: ?Sized, E>(
-    this: impl Init<T, E>,
-    f: impl FnOnce(&mut T) -> Result<(), E>,
-) -> impl Init<T, E> {
-    unsafe {
-        init_from_closure(|slot: *mut T| {
-            this.__init(slot)?;
-
-            f(&mut *slot).map_err(|e| {
-                // SAFETY: The value was initialized above, and since we return
-                // `Err` here, the caller will consider the memory at `slot` to
-                // be uninitialized.
-                ptr::drop_in_place(slot);
-                e
-            })
-        })
+/// Created by [`Init::map_err`].
+#[doc(hidden)]
+pub struct MapErrInit<I, F, T: ?Sized, E>(I, F, PhantomData<fn(*mut T) -> E>);
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` and cleans up the slot on error.
+unsafe impl<T: ?Sized, E, E2, I, F> Init<T, E2> for MapErrInit<I, F, T, E>
+where
+    I: Init<T, E>,
+    F: FnOnce(E) -> E2,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E2> {
+        // SAFETY: `slot` is valid, this is the exact same contract as `__init`. The inner
+        // initializer already cleaned up `slot` on error, so no additional cleanup is needed
+        // here; we only translate the error value.
+        unsafe { self.0.__init(slot).map_err(self.1) }
     }
 }
 
-/// Chains a closure to the pinned initializer to be called on successful
-/// initialization.
+/// An initializer that first initializes the value using `I` and then calls `F` with a mutable
+/// reference to that value.
 ///
-/// Returns a new initializer. If the closure returns `Err`, the object is
-/// dropped.
-// TODO: Once return_position_impl_trait_in_trait works, this should probably be
-// a trait method and called `and_then()` or so.
-pub fn pin_chain<T: ?Sized, E>(
-    this: impl PinInit<T, E>,
-    f: impl FnOnce(&mut T) -> Result<(), E>,
-) -> impl PinInit<T, E> {
-    unsafe {
-        init_from_closure(|slot: *mut T| {
-            this.__pinned_init(slot)?;
-
-            f(&mut *slot).map_err(|e| {
-                // SAFETY: The value was initialized above, and since we return
-                // `Err` here, the caller will consider the memory at `slot` to
-                // be uninitialized.
-                ptr::drop_in_place(slot);
-                e
-            })
+/// Created by [`Init::chain`].
+#[doc(hidden)]
+pub struct ChainInit<I, F, T: ?Sized, E>(I, F, PhantomData<fn(*mut T) -> E>);
+
+// SAFETY: The `__init` function is implemented such that it
+// - returns `Ok(())` on successful initialization,
+// - returns `Err(err)` and cleans up the slot on error.
+unsafe impl<T: ?Sized, E, I, F> Init<T, E> for ChainInit<I, F, T, E>
+where
+    I: Init<T, E>,
+    F: FnOnce(&mut T) -> Result<(), E>,
+{
+    unsafe fn __init(self, slot: *mut T) -> Result<(), E> {
+        // SAFETY: `slot` is valid, this is the exact same contract as `__init`.
+        unsafe { self.0.__init(slot)? };
+        // SAFETY: `slot` is now initialized, since the above call succeeded.
+        let val = unsafe { &mut *slot };
+        (self.1)(val).map_err(|e| {
+            // SAFETY: `slot` was initialized above and since we return `Err` here, the caller
+            // will consider the memory at `slot` to be uninitialized.
+            unsafe { ptr::drop_in_place(slot) };
+            e
         })
     }
 }
@@ -1385,6 +1454,64 @@ pub fn uninit<T, E>() -> impl Init<MaybeUninit<T>, E> {
     unsafe { init_from_closure(|_| Ok(())) }
 }
 
+/// Runs the given pin-initializer on `slot`, then returns a pinned reference to the
+/// now-initialized value.
+///
+/// This allows pin-initializing arbitrary caller-provided storage (e.g. DMA-coherent memory or a
+/// pre-reserved region), not just the smart pointers that implement [`InPlaceInit`].
+///
+/// # Safety
+///
+/// `slot` must stay valid, live and pinned until it is dropped, i.e. the caller may not move out
+/// of `*slot` nor deallocate it for the lifetime `'a`.
+///
+/// # Errors
+///
+/// If `init` fails, `slot` is left uninitialized and must not be treated as containing a valid
+/// `T` (i.e. [`assume_init_mut`] must not be called on it).
+///
+/// [`assume_init_mut`]: MaybeUninit::assume_init_mut
+pub unsafe fn pin_init_in_place<'a, T, E>(
+    mut slot: Pin<&'a mut MaybeUninit<T>>,
+    init: impl PinInit<T, E>,
+) -> Result<Pin<&'a mut T>, E> {
+    // SAFETY: We never move out of `slot`.
+    let ptr = unsafe { slot.as_mut().get_unchecked_mut() }.as_mut_ptr();
+    // SAFETY: `slot` is valid, and by the safety contract of this function it stays pinned and
+    // live until it is dropped.
+    unsafe { init.__pinned_init(ptr)? };
+    // SAFETY: `slot` has just been initialized above and stays pinned, since it was already
+    // pinned on entry.
+    Ok(unsafe { slot.map_unchecked_mut(|slot| slot.assume_init_mut()) })
+}
+
+/// Runs the given initializer on `slot`, then returns a reference to the now-initialized value.
+///
+/// This allows initializing arbitrary caller-provided storage (e.g. DMA-coherent memory or a
+/// pre-reserved region), not just the smart pointers that implement [`InPlaceInit`].
+///
+/// # Safety
+///
+/// `slot` must stay valid and live until it is dropped.
+///
+/// # Errors
+///
+/// If `init` fails, `slot` is left uninitialized and must not be treated as containing a valid
+/// `T` (i.e. [`assume_init_mut`] must not be called on it).
+///
+/// [`assume_init_mut`]: MaybeUninit::assume_init_mut
+pub unsafe fn init_in_place<'a, T, E>(
+    slot: &'a mut MaybeUninit<T>,
+    init: impl Init<T, E>,
+) -> Result<&'a mut T, E> {
+    let ptr = slot.as_mut_ptr();
+    // SAFETY: `slot` is valid, and by the safety contract of this function it stays live until it
+    // is dropped.
+    unsafe { init.__init(ptr)? };
+    // SAFETY: `slot` has just been initialized above.
+    Ok(unsafe { slot.assume_init_mut() })
+}
+
 /// Initializes an array by initializing each element via the provided initializer.
 ///
 /// # Examples
@@ -1393,6 +1520,25 @@ pub fn uninit<T, E>() -> impl Init<MaybeUninit<T>, E> {
 /// let array: Box<[usize; 1000_000_000]>= Box::init(init_array_from_fn(|i| i)).unwrap();
 /// println!("{array:?}");
 /// ```
+///
+/// It can also be used as a field initializer inside of [`try_init!`], e.g. to build a
+/// [`BigBuf`](try_init!#examples)-style struct whose large buffer is itself an array that is
+/// initialized in place, element by element:
+///
+/// ```rust
+/// use kernel::{init::{self, init_array_from_fn, Init}, error::Error};
+/// struct BigBuf {
+///     buckets: [[u8; 1024 * 1024]; 64],
+/// }
+///
+/// impl BigBuf {
+///     fn new() -> impl Init<Self, Error> {
+///         try_init!(Self {
+///             buckets <- init_array_from_fn(|_| init::zeroed()),
+///         }? Error)
+///     }
+/// }
+/// ```
 pub fn init_array_from_fn<I, const N: usize, T, E>(
     mut make_init: impl FnMut(usize) -> I,
 ) -> impl Init<[T; N], E>
@@ -1410,8 +1556,9 @@ where
             match unsafe { init.__init(ptr) } {
                 Ok(()) => {}
                 Err(e) => {
-                    // We now free every element that has been initialized before:
-                    for j in 0..i {
+                    // We now free every element that has been initialized before, in reverse
+                    // order, mirroring the drop order of the fields munched by `try_init!`.
+                    for j in (0..i).rev() {
                         let ptr = unsafe { slot.add(j) };
                         // SAFETY: The value was initialized in a previous iteration of the loop
                         // and since we return `Err` below, the caller will consider the memory at
@@ -1425,12 +1572,22 @@ where
         Ok(())
     };
     // SAFETY: The initializer above initializes every element of the array. On failure it drops
-    // any initialized elements and returns `Err`.
+    // any initialized elements (in reverse order) and returns `Err`.
     unsafe { init_from_closure(init) }
 }
 
 /// Initializes an array by initializing each element via the provided initializer.
 ///
+/// This is the pinned analogue of [`init_array_from_fn`] and should be preferred when the
+/// element type needs to be pinned, e.g. an array of [`Mutex`]es:
+///
+/// ```rust
+/// # use kernel::{sync::Mutex, init::pin_init_array_from_fn, InPlaceInit};
+/// # use core::pin::Pin;
+/// let _slots: Result<Pin<Box<[Mutex<usize>; 10]>>, _> =
+///     Box::pin_init(pin_init_array_from_fn(|i| Mutex::new_named(0, "slot")));
+/// ```
+///
 /// # Examples
 ///
 /// ```rust
@@ -1438,6 +1595,8 @@ where
 ///     Arc::pin_init(init_array_from_fn(|i| Mutex::new(i))).unwrap();
 /// println!("{array:?}");
 /// ```
+///
+/// [`Mutex`]: crate::sync::Mutex
 pub fn pin_init_array_from_fn<I, const N: usize, T, E>(
     mut make_init: impl FnMut(usize) -> I,
 ) -> impl PinInit<[T; N], E>
@@ -1455,9 +1614,9 @@ where
             match unsafe { init.__pinned_init(ptr) } {
                 Ok(()) => {}
                 Err(e) => {
-                    // We now have to free every element that has been initialized before, since we
-                    // have to abide by the drop guarantee.
-                    for j in 0..i {
+                    // We now have to free every element that has been initialized before, in
+                    // reverse order, since we have to abide by the drop guarantee.
+                    for j in (0..i).rev() {
                         let ptr = unsafe { slot.add(j) };
                         // SAFETY: The value was initialized in a previous iteration of the loop
                         // and since we return `Err` below, the caller will consider the memory at
@@ -1471,7 +1630,7 @@ where
         Ok(())
     };
     // SAFETY: The initializer above initializes every element of the array. On failure it drops
-    // any initialized elements and returns `Err`.
+    // any initialized elements (in reverse order) and returns `Err`.
     unsafe { pin_init_from_closure(init) }
 }
 
@@ -1592,6 +1751,108 @@ impl<T> InPlaceInit<T> for UniqueArc<T> {
     }
 }
 
+impl<T> InPlaceInit<T> for Arc<T> {
+    #[inline]
+    #[track_caller]
+    fn try_pin_init<E>(init: impl PinInit<T, E>) -> Result<Pin<Self>, E>
+    where
+        E: From<AllocError>,
+    {
+        let mut this = Arc::try_new_uninit()?;
+        let slot = this.as_mut_ptr();
+        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+        // slot is valid and will not be moved, because we pin it later.
+        unsafe { init.__pinned_init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { this.assume_init() }.into())
+    }
+
+    #[inline]
+    #[track_caller]
+    fn try_init<E>(init: impl Init<T, E>) -> Result<Self, E>
+    where
+        E: From<AllocError>,
+    {
+        let mut this = Arc::try_new_uninit()?;
+        let slot = this.as_mut_ptr();
+        // SAFETY: When init errors/panics, slot will get deallocated but not dropped,
+        // slot is valid.
+        unsafe { init.__init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { this.assume_init() })
+    }
+}
+
+/// Smart pointer containing uninitialized memory that can have an initializer written into it.
+///
+/// Unlike [`InPlaceInit`], this does not allocate a new smart pointer; it writes into storage the
+/// caller already holds (e.g. from a pre-allocation step done outside of an atomic/IRQ-disabled
+/// context), decoupling allocation from initialization.
+pub trait InPlaceWrite<T> {
+    /// The smart pointer type that results once the value has been initialized.
+    type Initialized;
+
+    /// Use the given initializer to write a `T` into `self`.
+    ///
+    /// If `init` fails, `self` is dropped without dropping the (uninitialized) `T`, and the error
+    /// is forwarded.
+    fn write_init<E>(self, init: impl Init<T, E>) -> Result<Self::Initialized, E>;
+
+    /// Use the given pin-initializer to write a `T` into `self`.
+    ///
+    /// If `init` fails, `self` is dropped without dropping the (uninitialized) `T`, and the error
+    /// is forwarded.
+    fn write_pin_init<E>(self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E>;
+}
+
+impl<T> InPlaceWrite<T> for Box<MaybeUninit<T>> {
+    type Initialized = Box<T>;
+
+    #[inline]
+    fn write_init<E>(mut self, init: impl Init<T, E>) -> Result<Self::Initialized, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: When init errors/panics, `self` will get deallocated but not dropped,
+        // slot is valid.
+        unsafe { init.__init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() })
+    }
+
+    #[inline]
+    fn write_pin_init<E>(mut self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: When init errors/panics, `self` will get deallocated but not dropped,
+        // slot is valid and will not be moved, because we pin it later.
+        unsafe { init.__pinned_init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() }.into())
+    }
+}
+
+impl<T> InPlaceWrite<T> for UniqueArc<MaybeUninit<T>> {
+    type Initialized = UniqueArc<T>;
+
+    #[inline]
+    fn write_init<E>(mut self, init: impl Init<T, E>) -> Result<Self::Initialized, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: When init errors/panics, `self` will get deallocated but not dropped,
+        // slot is valid.
+        unsafe { init.__init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() })
+    }
+
+    #[inline]
+    fn write_pin_init<E>(mut self, init: impl PinInit<T, E>) -> Result<Pin<Self::Initialized>, E> {
+        let slot = self.as_mut_ptr();
+        // SAFETY: When init errors/panics, `self` will get deallocated but not dropped,
+        // slot is valid and will not be moved, because we pin it later.
+        unsafe { init.__pinned_init(slot)? };
+        // SAFETY: All fields have been initialized.
+        Ok(unsafe { self.assume_init() }.into())
+    }
+}
+
 /// Trait facilitating pinned destruction.
 ///
 /// Use [`pinned_drop`] to implement this trait safely:
@@ -1641,6 +1902,37 @@ pub unsafe trait PinnedDrop: __internal::HasPinData {
 /// ```rust,ignore
 /// let val: Self = unsafe { core::mem::zeroed() };
 /// ```
+///
+/// # Implementing `Zeroable` for an enum
+///
+/// `#[derive(Zeroable)]` only supports `struct`s. For a C-derived enum where the all-zero bit
+/// pattern happens to be a valid, meaningful variant, implement this trait by hand instead:
+///
+/// ```rust
+/// # use kernel::init::Zeroable;
+/// #[repr(C)]
+/// enum State {
+///     Idle = 0,
+///     Active(u32),
+/// }
+///
+/// // SAFETY: The all-zero bit pattern has discriminant `0`, which is `Self::Idle`, a unit
+/// // variant with no fields that need to be zeroable themselves.
+/// unsafe impl Zeroable for State {}
+/// ```
+///
+/// When writing such an `unsafe impl`, the non-negotiable parts of the safety proof are that
+/// *exactly one* variant has (explicitly or implicitly) discriminant `0`, and that every field of
+/// that variant is itself [`Zeroable`].
+///
+/// # On deriving `Zeroable` for structs
+///
+/// `#[derive(Zeroable)]` comes from the `macros` crate, alongside `#[pin_data]` and
+/// `#[pinned_drop]` (see the `..Zeroable::zeroed()` example of [`pin_init!`]). It implements this
+/// trait for a `struct` and requires every field's type to be [`Zeroable`] itself, so the compiler
+/// checks the safety condition above field by field instead of the user asserting it by hand.
+/// `union`s and `enum`s are not supported by the derive, since their all-zero validity cannot be
+/// derived from their fields alone (see the manual-impl guidance above for enums).
 pub unsafe trait Zeroable: core::marker::Sized {
     /// Create a new zeroed T.
     ///